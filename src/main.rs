@@ -2,13 +2,25 @@ use chrono::Local;
 use clap::{Parser, Subcommand};
 use colored::*;
 use crossterm::{
+    cursor::Show,
     execute,
+    style::ResetColor,
     terminal::{Clear, ClearType},
 };
 use log::warn;
 use serde::{Deserialize, Serialize};
-use std::{fs, io::Write, path::Path, thread, time::Duration};
-use sysinfo::{Disks, System};
+use std::{
+    collections::{HashMap, VecDeque},
+    fs,
+    io::Write,
+    path::Path,
+    thread,
+    time::{Duration, Instant},
+};
+use sysinfo::{Components, Disks, Networks, System};
+
+mod query;
+use query::Query;
 
 #[derive(Parser)]
 #[command(name = "system_monitor")]
@@ -33,6 +45,10 @@ struct Cli {
     /// Log file path
     #[arg(short, long, default_value = "system_monitor.log")]
     log_file: String,
+
+    /// Filter the process list with a query, e.g. "cpu>50 and name:firefox"
+    #[arg(long)]
+    filter: Option<String>,
 }
 
 #[derive(Subcommand)]
@@ -43,6 +59,29 @@ enum Commands {
     Summary,
     /// Generate sample config file
     GenerateConfig,
+    /// Terminate a process by PID or name
+    Kill {
+        /// Numeric PID, or a name pattern matching one or more processes
+        target: String,
+        /// Signal to send (TERM, KILL, INT, HUP, QUIT, USR1, USR2). Defaults to a plain kill.
+        #[arg(long)]
+        signal: Option<String>,
+    },
+    /// Export a history buffer of snapshots to CSV or JSON
+    Export {
+        /// Output format: "csv" or "json"
+        format: String,
+        /// Output file path
+        out: String,
+        /// Stream one JSON object per refresh instead of writing a final batch export
+        #[arg(long)]
+        stream: bool,
+        /// Number of refreshes to collect before writing the batch export (omit to
+        /// collect until interrupted; on Unix, Ctrl+C or SIGTERM flushes what was
+        /// gathered so far, elsewhere Ctrl+C exits without writing the export)
+        #[arg(long)]
+        cycles: Option<u64>,
+    },
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -51,6 +90,10 @@ struct Config {
     display: DisplayConfig,
     thresholds: ThresholdsConfig,
     alerts: AlertsConfig,
+    network: NetworkConfig,
+    processes: ProcessesConfig,
+    history: HistoryConfig,
+    idle: IdleConfig,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -66,6 +109,7 @@ struct DisplayConfig {
     use_colors: bool,
     show_per_core_cpu: bool,
     max_processes_to_display: usize,
+    temperature_unit: String,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -78,6 +122,8 @@ struct ThresholdsConfig {
     disk_critical: f32,
     swap_warning: f32,
     swap_critical: f32,
+    temp_warning: f32,
+    temp_critical: f32,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -85,6 +131,63 @@ struct AlertsConfig {
     enable_desktop_notifications: bool,
     enable_email_alerts: bool,
     enable_sound_alerts: bool,
+    cooldown_secs: u64,
+    email: EmailConfig,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct EmailConfig {
+    smtp_server: String,
+    smtp_port: u16,
+    username: String,
+    password: String,
+    from_address: String,
+    to_address: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct NetworkConfig {
+    interfaces: InterfaceFilter,
+    rx_warning: f64,
+    rx_critical: f64,
+    tx_warning: f64,
+    tx_critical: f64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct InterfaceFilter {
+    allow: Vec<String>,
+    deny: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct ProcessesConfig {
+    sort_by: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct HistoryConfig {
+    history_length: usize,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct IdleConfig {
+    enabled: bool,
+    idle_after_secs: u64,
+    idle_interval: u64,
+    cpu_threshold: f32,
+    network_threshold_mb: f64,
+    disk_delta_threshold: f32,
+}
+
+#[derive(Serialize, Debug, Clone)]
+struct Snapshot {
+    timestamp: String,
+    cpu_usage: f32,
+    memory_usage: f32,
+    swap_usage: f32,
+    disk_usage: HashMap<String, f32>,
+    network_rates: HashMap<String, (f64, f64)>,
 }
 
 impl Default for Config {
@@ -101,6 +204,7 @@ impl Default for Config {
                 use_colors: true,
                 show_per_core_cpu: true,
                 max_processes_to_display: 10,
+                temperature_unit: "celsius".to_string(),
             },
 
             thresholds: ThresholdsConfig {
@@ -112,17 +216,68 @@ impl Default for Config {
                 disk_critical: 90.0,
                 swap_warning: 70.0,
                 swap_critical: 90.0,
+                temp_warning: 70.0,
+                temp_critical: 85.0,
             },
 
             alerts: AlertsConfig {
                 enable_desktop_notifications: false,
                 enable_email_alerts: false,
                 enable_sound_alerts: false,
+                cooldown_secs: 300,
+                email: EmailConfig {
+                    smtp_server: "smtp.example.com".to_string(),
+                    smtp_port: 587,
+                    username: String::new(),
+                    password: String::new(),
+                    from_address: "system-monitor@example.com".to_string(),
+                    to_address: String::new(),
+                },
+            },
+
+            network: NetworkConfig {
+                interfaces: InterfaceFilter {
+                    allow: Vec::new(),
+                    deny: Vec::new(),
+                },
+                rx_warning: 10.0,
+                rx_critical: 50.0,
+                tx_warning: 10.0,
+                tx_critical: 50.0,
+            },
+
+            processes: ProcessesConfig {
+                sort_by: "cpu".to_string(),
+            },
+
+            history: HistoryConfig {
+                history_length: 120,
+            },
+
+            idle: IdleConfig {
+                enabled: false,
+                idle_after_secs: 300,
+                idle_interval: 120,
+                cpu_threshold: 5.0,
+                network_threshold_mb: 1.0,
+                disk_delta_threshold: 0.5,
             },
         }
     }
 }
 
+fn interface_allowed(name: &str, filter: &InterfaceFilter) -> bool {
+    if filter.deny.iter().any(|d| name.contains(d.as_str())) {
+        return false;
+    }
+
+    if !filter.allow.is_empty() {
+        return filter.allow.iter().any(|a| name.contains(a.as_str()));
+    }
+
+    true
+}
+
 fn load_config(path: &str) -> Config {
     if Path::new(path).exists() {
         let file = fs::read_to_string(path).expect("Failed to read config file");
@@ -159,6 +314,22 @@ fn get_usage_color(
     }
 }
 
+fn convert_temperature(celsius: f32, unit: &str) -> f32 {
+    if unit.eq_ignore_ascii_case("fahrenheit") {
+        celsius * 9.0 / 5.0 + 32.0
+    } else {
+        celsius
+    }
+}
+
+fn temperature_unit_label(unit: &str) -> &str {
+    if unit.eq_ignore_ascii_case("fahrenheit") {
+        "°F"
+    } else {
+        "°C"
+    }
+}
+
 fn create_progress_bar(usage: f32, warning: f32, critical: f32, use_colors: bool) -> String {
     if !use_colors {
         return format!("[{:>6.1}%]", usage);
@@ -173,6 +344,24 @@ fn create_progress_bar(usage: f32, warning: f32, critical: f32, use_colors: bool
     format!("[{}] {:>6.1}%", bar.color(color), usage)
 }
 
+/// Like `create_progress_bar`, but for an unbounded rate (MB/s) rather than a
+/// percentage: the bar fills relative to `critical` instead of a fixed 100,
+/// and the value is labeled "MB/s" instead of "%".
+fn create_rate_bar(rate: f32, warning: f32, critical: f32, use_colors: bool) -> String {
+    if !use_colors {
+        return format!("[{:>6.1} MB/s]", rate);
+    }
+
+    let width = 20;
+    let filled_fraction = if critical > 0.0 { rate / critical } else { 0.0 };
+    let filled_length = ((filled_fraction * width as f32) as usize).min(width);
+    let empty_length = width - filled_length;
+
+    let color = get_usage_color(rate, warning, critical, use_colors);
+    let bar = "#".repeat(filled_length) + &"-".repeat(empty_length);
+    format!("[{}] {:>6.1} MB/s", bar.color(color), rate)
+}
+
 fn log_alert(message: &str, config: &Config) {
     if config.general.log_alerts {
         let timestamp = Local::now().format("%Y-%m-%d %H:%M:%S");
@@ -191,17 +380,149 @@ fn log_alert(message: &str, config: &Config) {
     warn!("{}", message);
 }
 
-fn check_and_alert(name: &str, usage: f32, warning: f32, critical: f32, config: &Config) {
-    if usage >= critical {
-        let message = format!("{} usage is critical: {:.1}%", name, usage);
-        log_alert(&message, config);
-    } else if usage >= warning {
-        let message = format!("{} usage is high: {:.1}%", name, usage);
-        log_alert(&message, config);
+/// Tracks whether an alert key is currently "armed" (allowed to fire) and
+/// when it last fired, so a metric sitting above a threshold for many
+/// refresh cycles doesn't spam notifications.
+struct AlertState {
+    active: HashMap<String, bool>,
+    last_notified: HashMap<String, Instant>,
+}
+
+impl AlertState {
+    fn new() -> Self {
+        Self {
+            active: HashMap::new(),
+            last_notified: HashMap::new(),
+        }
+    }
+
+    /// Returns true if an alert for `key` should be dispatched now, and
+    /// records that it was. Suppresses repeats until the metric drops back
+    /// below its warning threshold (see `rearm`) and the cooldown elapses.
+    fn should_notify(&mut self, key: &str, cooldown_secs: u64) -> bool {
+        if *self.active.get(key).unwrap_or(&false) {
+            return false;
+        }
+
+        if self
+            .last_notified
+            .get(key)
+            .is_some_and(|last| last.elapsed().as_secs() < cooldown_secs)
+        {
+            return false;
+        }
+
+        self.active.insert(key.to_string(), true);
+        self.last_notified.insert(key.to_string(), Instant::now());
+        true
+    }
+
+    /// Re-arms `key` once its metric has dropped back below the warning
+    /// threshold.
+    fn rearm(&mut self, key: &str) {
+        self.active.insert(key.to_string(), false);
     }
 }
 
-fn display_system_info(sys: &System, disks: &Disks, config: &Config) {
+fn send_desktop_notification(title: &str, body: &str) {
+    if let Err(e) = notify_rust::Notification::new()
+        .summary(title)
+        .body(body)
+        .show()
+    {
+        eprintln!("Failed to send desktop notification: {}", e);
+    }
+}
+
+fn play_alert_sound() {
+    print!("\x07");
+    let _ = std::io::stdout().flush();
+}
+
+fn send_email_alert(subject: &str, body: &str, email: &EmailConfig) -> Result<(), Box<dyn std::error::Error>> {
+    use lettre::transport::smtp::authentication::Credentials;
+    use lettre::{Message, SmtpTransport, Transport};
+
+    let message = Message::builder()
+        .from(email.from_address.parse()?)
+        .to(email.to_address.parse()?)
+        .subject(format!("System Monitor Alert: {}", subject))
+        .body(body.to_string())?;
+
+    let mailer = SmtpTransport::relay(&email.smtp_server)?
+        .port(email.smtp_port)
+        .credentials(Credentials::new(email.username.clone(), email.password.clone()))
+        .build();
+
+    mailer.send(&message)?;
+    Ok(())
+}
+
+fn dispatch_alert(name: &str, message: &str, config: &Config) {
+    if config.alerts.enable_desktop_notifications {
+        send_desktop_notification(&format!("System Monitor: {}", name), message);
+    }
+
+    if config.alerts.enable_sound_alerts {
+        play_alert_sound();
+    }
+
+    if config.alerts.enable_email_alerts {
+        send_email_alert(name, message, &config.alerts.email)
+            .unwrap_or_else(|e| eprintln!("Failed to send email alert: {}", e));
+    }
+}
+
+fn check_and_alert(
+    name: &str,
+    usage: f32,
+    warning: f32,
+    critical: f32,
+    unit: &str,
+    config: &Config,
+    alert_state: &mut AlertState,
+) {
+    if usage >= warning {
+        let message = if usage >= critical {
+            format!("{} usage is critical: {:.1}{}", name, usage, unit)
+        } else {
+            format!("{} usage is high: {:.1}{}", name, usage, unit)
+        };
+
+        if alert_state.should_notify(name, config.alerts.cooldown_secs) {
+            log_alert(&message, config);
+            dispatch_alert(name, &message, config);
+        }
+    } else {
+        alert_state.rearm(name);
+    }
+}
+
+/// Bundles the refreshed system-data handles a single `display_system_info`
+/// call needs, so the function itself doesn't take one parameter per handle.
+struct SystemSnapshot<'a> {
+    sys: &'a System,
+    disks: &'a Disks,
+    networks: &'a Networks,
+    network_rates: &'a HashMap<String, (f64, f64)>,
+    components: &'a Components,
+}
+
+fn display_system_info(
+    snapshot: &SystemSnapshot,
+    config: &Config,
+    alert_state: &mut AlertState,
+    filter: Option<&Query>,
+    signal_requested: bool,
+) {
+    let SystemSnapshot {
+        sys,
+        disks,
+        networks,
+        network_rates,
+        components,
+    } = *snapshot;
+
     let timestamp = Local::now().format("%Y-%m-%d %H:%M:%S");
 
     if config.display.use_colors {
@@ -236,7 +557,9 @@ fn display_system_info(sys: &System, disks: &Disks, config: &Config) {
         cpu_usage,
         config.thresholds.cpu_warning,
         config.thresholds.cpu_critical,
+        "%",
         config,
+        alert_state,
     );
 
     println!("\n{}", "CPU INFORMATION".cyan().bold());
@@ -276,7 +599,9 @@ fn display_system_info(sys: &System, disks: &Disks, config: &Config) {
         memory_usage as f32,
         config.thresholds.memory_warning,
         config.thresholds.memory_critical,
+        "%",
         config,
+        alert_state,
     );
 
     println!("\n{}", "MEMORY INFORMATION".cyan().bold());
@@ -317,7 +642,9 @@ fn display_system_info(sys: &System, disks: &Disks, config: &Config) {
             swap_usage as f32,
             config.thresholds.swap_warning,
             config.thresholds.swap_critical,
+            "%",
             config,
+            alert_state,
         );
         println!(
             "Swap Usage: {}",
@@ -349,7 +676,9 @@ fn display_system_info(sys: &System, disks: &Disks, config: &Config) {
             disk_usage as f32,
             config.thresholds.disk_warning,
             config.thresholds.disk_critical,
+            "%",
             config,
+            alert_state,
         );
 
         println!(
@@ -370,10 +699,113 @@ fn display_system_info(sys: &System, disks: &Disks, config: &Config) {
         );
     }
 
+    // Network Information - ALWAYS SHOW ALLOWED INTERFACES
+    println!("\n{}", "NETWORK INFORMATION".cyan().bold());
+    for (name, _data) in networks {
+        if !interface_allowed(name, &config.network.interfaces) {
+            continue;
+        }
+
+        match network_rates.get(name) {
+            Some(&(rx_rate, tx_rate)) => {
+                let rx_mb = (rx_rate / 1024.0 / 1024.0) as f32;
+                let tx_mb = (tx_rate / 1024.0 / 1024.0) as f32;
+
+                check_and_alert(
+                    &format!("Network {} RX", name),
+                    rx_mb,
+                    config.network.rx_warning as f32,
+                    config.network.rx_critical as f32,
+                    " MB/s",
+                    config,
+                    alert_state,
+                );
+                check_and_alert(
+                    &format!("Network {} TX", name),
+                    tx_mb,
+                    config.network.tx_warning as f32,
+                    config.network.tx_critical as f32,
+                    " MB/s",
+                    config,
+                    alert_state,
+                );
+
+                println!(
+                    "{:<12} RX: {}  TX: {}",
+                    name,
+                    create_rate_bar(
+                        rx_mb,
+                        config.network.rx_warning as f32,
+                        config.network.rx_critical as f32,
+                        config.display.use_colors
+                    ),
+                    create_rate_bar(
+                        tx_mb,
+                        config.network.tx_warning as f32,
+                        config.network.tx_critical as f32,
+                        config.display.use_colors
+                    )
+                );
+            }
+            None => {
+                println!("{:<12} RX: --  TX: --", name);
+            }
+        }
+    }
+
+    // Temperature Information - ALWAYS SHOW AVAILABLE SENSORS
+    println!("\n{}", "TEMPERATURE INFORMATION".cyan().bold());
+    let unit = &config.display.temperature_unit;
+    let unit_label = temperature_unit_label(unit);
+
+    for component in components {
+        let critical = match component.critical() {
+            Some(critical) => critical,
+            None => {
+                println!("{:<24} {:.1}{}", component.label(), convert_temperature(component.temperature(), unit), unit_label);
+                continue;
+            }
+        };
+
+        let temp = component.temperature();
+        check_and_alert(
+            &format!("Temperature {}", component.label()),
+            temp,
+            config.thresholds.temp_warning,
+            config.thresholds.temp_critical,
+            "°C",
+            config,
+            alert_state,
+        );
+
+        let color = get_usage_color(
+            temp / critical * 100.0,
+            config.thresholds.temp_warning / critical * 100.0,
+            config.thresholds.temp_critical / critical * 100.0,
+            config.display.use_colors,
+        );
+
+        println!(
+            "{:<24} {}",
+            component.label(),
+            format!("{:.1}{}", convert_temperature(temp, unit), unit_label).color(color)
+        );
+    }
+
     // Process Information - ALWAYS SHOW TOP PROCESSES
     println!("\n{}", "TOP PROCESSES".cyan().bold());
     let mut processes: Vec<_> = sys.processes().values().collect();
-    processes.sort_by(|a, b| b.cpu_usage().partial_cmp(&a.cpu_usage()).unwrap());
+
+    if let Some(filter) = filter {
+        processes.retain(|process| query::matches(filter, process));
+    }
+
+    match config.processes.sort_by.as_str() {
+        "memory" => processes.sort_by_key(|p| std::cmp::Reverse(p.memory())),
+        "pid" => processes.sort_by_key(|p| p.pid()),
+        "name" => processes.sort_by(|a, b| a.name().cmp(b.name())),
+        _ => processes.sort_by(|a, b| b.cpu_usage().partial_cmp(&a.cpu_usage()).unwrap()),
+    }
 
     println!("{:<8} {:<20} {:<8} {:<10}", "PID", "NAME", "CPU%", "MEMORY");
     println!("{}", "-".repeat(50));
@@ -412,25 +844,475 @@ fn display_system_info(sys: &System, disks: &Disks, config: &Config) {
         load_avg.one, load_avg.five, load_avg.fifteen
     );
 
+    if signal_requested {
+        println!("\n{}", "(snapshot requested via SIGUSR1)".bright_black());
+    }
+
     println!("\n{}", "Press Ctrl+C to exit...".bright_black());
 }
 
-fn run_monitor(config: &Config, once: bool) {
+/// Installs SIGTERM/SIGINT/SIGUSR1 handlers backed by atomic flags, so the
+/// signal handlers themselves stay async-signal-safe and all real work
+/// happens when the flags are checked at the top of the monitor loop.
+#[cfg(unix)]
+struct SignalState {
+    shutdown: std::sync::Arc<std::sync::atomic::AtomicBool>,
+    snapshot: std::sync::Arc<std::sync::atomic::AtomicBool>,
+}
+
+#[cfg(unix)]
+impl SignalState {
+    fn install() -> Self {
+        use std::sync::atomic::AtomicBool;
+        use std::sync::Arc;
+
+        let shutdown = Arc::new(AtomicBool::new(false));
+        let snapshot = Arc::new(AtomicBool::new(false));
+
+        signal_hook::flag::register(signal_hook::consts::SIGTERM, Arc::clone(&shutdown))
+            .expect("Failed to register SIGTERM handler");
+        signal_hook::flag::register(signal_hook::consts::SIGINT, Arc::clone(&shutdown))
+            .expect("Failed to register SIGINT handler");
+        signal_hook::flag::register(signal_hook::consts::SIGUSR1, Arc::clone(&snapshot))
+            .expect("Failed to register SIGUSR1 handler");
+
+        Self { shutdown, snapshot }
+    }
+
+    fn shutdown_requested(&self) -> bool {
+        self.shutdown.load(std::sync::atomic::Ordering::Relaxed)
+    }
+
+    /// Peeks at a pending snapshot request without consuming it, so a sleep
+    /// loop can wake up early without racing the actual consumer, which runs
+    /// just before `display_system_info` in the next iteration.
+    fn snapshot_pending(&self) -> bool {
+        self.snapshot.load(std::sync::atomic::Ordering::Relaxed)
+    }
+
+    fn take_snapshot_request(&self) -> bool {
+        self.snapshot.swap(false, std::sync::atomic::Ordering::Relaxed)
+    }
+}
+
+fn run_monitor(config: &Config, once: bool, filter: Option<&Query>) {
     let mut sys = System::new_all();
     let mut disks = Disks::new_with_refreshed_list();
+    let mut networks = Networks::new_with_refreshed_list();
+    let mut components = Components::new_with_refreshed_list();
+
+    let mut prev_network: HashMap<String, (u64, u64)> = HashMap::new();
+    let mut prev_disk_usage: HashMap<String, f32> = HashMap::new();
+    let mut prev_instant: Option<Instant> = None;
+    let mut prev_wallclock: Option<chrono::DateTime<Local>> = None;
+    let mut alert_state = AlertState::new();
+
+    let mut current_interval = config.general.refresh_interval;
+    let mut last_interval_used = config.general.refresh_interval;
+    let mut is_idle = false;
+    let mut below_threshold_since: Option<Instant> = None;
+    let mut below_threshold_wallclock: Option<chrono::DateTime<Local>> = None;
+
+    #[cfg(unix)]
+    let signals = SignalState::install();
 
     loop {
+        #[cfg(unix)]
+        if signals.shutdown_requested() {
+            execute!(std::io::stdout(), ResetColor, Show, Clear(ClearType::All)).ok();
+            println!("{}", "Shutdown signal received, exiting...".bright_black());
+            log_alert("Monitor shutting down (signal received)", config);
+            break;
+        }
+
         sys.refresh_all();
         disks.refresh();
+        networks.refresh();
+        components.refresh();
+
+        let now = Instant::now();
+        let now_wallclock = Local::now();
+        let elapsed_secs = prev_instant.map(|prev| now.duration_since(prev).as_secs_f64());
+        let wallclock_elapsed_secs =
+            prev_wallclock.map(|prev| (now_wallclock - prev).num_milliseconds() as f64 / 1000.0);
+
+        let expected_margin_secs = 5.0;
+        if let Some(elapsed) = wallclock_elapsed_secs
+            .filter(|&elapsed| elapsed > last_interval_used as f64 * 2.0 + expected_margin_secs)
+        {
+            log_alert(
+                &format!(
+                    "Resume from suspend detected ({:.0}s elapsed, expected ~{}s)",
+                    elapsed, last_interval_used
+                ),
+                config,
+            );
+            is_idle = false;
+            current_interval = config.general.refresh_interval;
+            below_threshold_since = None;
+            below_threshold_wallclock = None;
+        }
+
+        let mut network_rates: HashMap<String, (f64, f64)> = HashMap::new();
+        for (name, data) in &networks {
+            let rx_now = data.total_received();
+            let tx_now = data.total_transmitted();
+
+            if let (Some(elapsed), Some(&(rx_prev, tx_prev))) =
+                (elapsed_secs.filter(|&e| e > 0.0), prev_network.get(name))
+            {
+                let rx_rate = rx_now.saturating_sub(rx_prev) as f64 / elapsed;
+                let tx_rate = tx_now.saturating_sub(tx_prev) as f64 / elapsed;
+                network_rates.insert(name.clone(), (rx_rate, tx_rate));
+            }
+
+            prev_network.insert(name.clone(), (rx_now, tx_now));
+        }
+
+        if config.idle.enabled {
+            let cpu_usage = sys.global_cpu_info().cpu_usage();
+            let network_total_mb: f64 = network_rates
+                .values()
+                .map(|&(rx, tx)| (rx + tx) / 1024.0 / 1024.0)
+                .sum();
 
-        display_system_info(&sys, &disks, config);
+            let mut disk_delta = 0.0_f32;
+            for disk in &disks {
+                let total_space = disk.total_space();
+                let available_space = disk.available_space();
+                let usage = if total_space > 0 {
+                    ((total_space - available_space) as f64 / total_space as f64 * 100.0) as f32
+                } else {
+                    0.0
+                };
+
+                let mount = disk.mount_point().display().to_string();
+                if let Some(&prev_usage) = prev_disk_usage.get(&mount) {
+                    disk_delta = disk_delta.max((usage - prev_usage).abs());
+                }
+                prev_disk_usage.insert(mount, usage);
+            }
+
+            let is_quiet = cpu_usage < config.idle.cpu_threshold
+                && network_total_mb < config.idle.network_threshold_mb
+                && disk_delta < config.idle.disk_delta_threshold;
+
+            if is_quiet {
+                let since = *below_threshold_since.get_or_insert(now);
+                if below_threshold_wallclock.is_none() {
+                    below_threshold_wallclock = Some(Local::now());
+                }
+
+                if !is_idle && now.duration_since(since).as_secs() >= config.idle.idle_after_secs {
+                    is_idle = true;
+                    current_interval = config.idle.idle_interval;
+                    if let Some(since_wallclock) = below_threshold_wallclock {
+                        println!(
+                            "{}",
+                            format!("idle since {}", since_wallclock.format("%H:%M")).bright_black()
+                        );
+                    }
+                }
+            } else {
+                below_threshold_since = None;
+                below_threshold_wallclock = None;
+                if is_idle {
+                    is_idle = false;
+                    current_interval = config.general.refresh_interval;
+                }
+            }
+        }
+
+        prev_instant = Some(now);
+        prev_wallclock = Some(now_wallclock);
+
+        let snapshot = SystemSnapshot {
+            sys: &sys,
+            disks: &disks,
+            networks: &networks,
+            network_rates: &network_rates,
+            components: &components,
+        };
+
+        #[cfg(unix)]
+        let signal_requested = signals.take_snapshot_request();
+        #[cfg(not(unix))]
+        let signal_requested = false;
+
+        display_system_info(&snapshot, config, &mut alert_state, filter, signal_requested);
 
         if once {
             break;
         }
 
+        last_interval_used = current_interval;
+
+        // Sleep in short slices so a SIGUSR1 snapshot request or shutdown signal is
+        // picked up immediately instead of waiting out the full refresh interval.
+        #[cfg(unix)]
+        {
+            let poll_interval = Duration::from_millis(200);
+            let mut remaining = Duration::from_secs(current_interval);
+            while remaining > Duration::ZERO
+                && !signals.shutdown_requested()
+                && !signals.snapshot_pending()
+            {
+                let step = poll_interval.min(remaining);
+                thread::sleep(step);
+                remaining = remaining.saturating_sub(step);
+            }
+        }
+        #[cfg(not(unix))]
+        thread::sleep(Duration::from_secs(current_interval));
+    }
+}
+
+fn signal_from_str(name: &str) -> Option<sysinfo::Signal> {
+    match name.to_ascii_uppercase().as_str() {
+        "TERM" => Some(sysinfo::Signal::Term),
+        "KILL" => Some(sysinfo::Signal::Kill),
+        "INT" => Some(sysinfo::Signal::Interrupt),
+        "HUP" => Some(sysinfo::Signal::Hangup),
+        "QUIT" => Some(sysinfo::Signal::Quit),
+        "USR1" => Some(sysinfo::Signal::User1),
+        "USR2" => Some(sysinfo::Signal::User2),
+        _ => None,
+    }
+}
+
+fn resolve_kill_targets(sys: &System, target: &str) -> Vec<sysinfo::Pid> {
+    if let Ok(pid) = target.parse::<usize>() {
+        return vec![sysinfo::Pid::from(pid)];
+    }
+
+    sys.processes()
+        .iter()
+        .filter(|(_, process)| process.name().contains(target))
+        .map(|(pid, _)| *pid)
+        .collect()
+}
+
+fn kill_processes(config: &Config, target: &str, signal: Option<&str>, quiet: bool) {
+    let mut sys = System::new_all();
+    sys.refresh_all();
+
+    let pids = resolve_kill_targets(&sys, target);
+    if pids.is_empty() {
+        println!("No processes matched '{}'", target);
+        return;
+    }
+
+    if !quiet {
+        println!("The following processes will be killed:");
+        for pid in &pids {
+            if let Some(process) = sys.process(*pid) {
+                println!("  {:<8} {}", pid, process.name());
+            }
+        }
+
+        print!("Proceed? [y/N] ");
+        std::io::stdout().flush().ok();
+
+        let mut input = String::new();
+        std::io::stdin().read_line(&mut input).ok();
+        if !input.trim().eq_ignore_ascii_case("y") {
+            println!("Aborted.");
+            return;
+        }
+    }
+
+    let sig = match signal {
+        Some(name) => match signal_from_str(name) {
+            Some(sig) => Some(sig),
+            None => {
+                eprintln!("Unknown signal '{}', sending a plain kill instead", name);
+                None
+            }
+        },
+        None => None,
+    };
+
+    for pid in pids {
+        let Some(process) = sys.process(pid) else {
+            println!("{:<8} no longer exists", pid);
+            continue;
+        };
+
+        let name = process.name().to_string();
+        let success = match sig {
+            Some(sig) => process.kill_with(sig).unwrap_or_else(|| process.kill()),
+            None => process.kill(),
+        };
+
+        let message = if success {
+            format!("Killed process {} ({})", pid, name)
+        } else {
+            format!("Failed to kill process {} ({})", pid, name)
+        };
+
+        println!("{}", message);
+        log_alert(&message, config);
+    }
+}
+
+fn build_snapshot(sys: &System, disks: &Disks, network_rates: &HashMap<String, (f64, f64)>) -> Snapshot {
+    let total_memory = sys.total_memory();
+    let used_memory = sys.used_memory();
+    let memory_usage = if total_memory > 0 {
+        (used_memory as f64 / total_memory as f64 * 100.0) as f32
+    } else {
+        0.0
+    };
+
+    let total_swap = sys.total_swap();
+    let used_swap = sys.used_swap();
+    let swap_usage = if total_swap > 0 {
+        (used_swap as f64 / total_swap as f64 * 100.0) as f32
+    } else {
+        0.0
+    };
+
+    let mut disk_usage = HashMap::new();
+    for disk in disks {
+        let total_space = disk.total_space();
+        let available_space = disk.available_space();
+        let usage = if total_space > 0 {
+            ((total_space - available_space) as f64 / total_space as f64 * 100.0) as f32
+        } else {
+            0.0
+        };
+        disk_usage.insert(disk.mount_point().display().to_string(), usage);
+    }
+
+    Snapshot {
+        timestamp: Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+        cpu_usage: sys.global_cpu_info().cpu_usage(),
+        memory_usage,
+        swap_usage,
+        disk_usage,
+        network_rates: network_rates.clone(),
+    }
+}
+
+fn write_snapshot_line(snapshot: &Snapshot, out: &str) {
+    let line = serde_json::to_string(snapshot).expect("Failed to serialize snapshot");
+
+    if out == "-" {
+        println!("{}", line);
+        return;
+    }
+
+    if let Err(e) = fs::OpenOptions::new()
+        .append(true)
+        .create(true)
+        .open(out)
+        .and_then(|mut file| writeln!(file, "{}", line))
+    {
+        eprintln!("Failed to write snapshot to {}: {}", out, e);
+    }
+}
+
+fn csv_field(value: &str) -> String {
+    format!("\"{}\"", value.replace('"', "\"\""))
+}
+
+fn write_history(history: &VecDeque<Snapshot>, format: &str, out: &str) -> Result<(), Box<dyn std::error::Error>> {
+    match format {
+        "csv" => {
+            let mut csv = String::from("timestamp,cpu_usage,memory_usage,swap_usage,disk_usage,network_rates\n");
+            for snapshot in history {
+                csv.push_str(&format!(
+                    "{},{:.1},{:.1},{:.1},{},{}\n",
+                    snapshot.timestamp,
+                    snapshot.cpu_usage,
+                    snapshot.memory_usage,
+                    snapshot.swap_usage,
+                    csv_field(&serde_json::to_string(&snapshot.disk_usage)?),
+                    csv_field(&serde_json::to_string(&snapshot.network_rates)?)
+                ));
+            }
+            fs::write(out, csv)?;
+        }
+        "json" => {
+            let snapshots: Vec<&Snapshot> = history.iter().collect();
+            fs::write(out, serde_json::to_string_pretty(&snapshots)?)?;
+        }
+        other => return Err(format!("unknown export format '{}' (expected \"csv\" or \"json\")", other).into()),
+    }
+
+    Ok(())
+}
+
+fn export_history(config: &Config, format: &str, out: &str, stream: bool, once: bool, cycles: Option<u64>) {
+    let mut sys = System::new_all();
+    let mut disks = Disks::new_with_refreshed_list();
+    let mut networks = Networks::new_with_refreshed_list();
+
+    let mut prev_network: HashMap<String, (u64, u64)> = HashMap::new();
+    let mut prev_instant: Option<Instant> = None;
+    let mut history: VecDeque<Snapshot> = VecDeque::with_capacity(config.history.history_length);
+    let mut collected: u64 = 0;
+
+    #[cfg(unix)]
+    let signals = SignalState::install();
+
+    loop {
+        #[cfg(unix)]
+        if signals.shutdown_requested() {
+            println!("{}", "Interrupted, writing what was collected so far...".bright_black());
+            break;
+        }
+
+        sys.refresh_all();
+        disks.refresh();
+        networks.refresh();
+
+        let now = Instant::now();
+        let elapsed_secs = prev_instant.map(|prev| now.duration_since(prev).as_secs_f64());
+
+        let mut network_rates: HashMap<String, (f64, f64)> = HashMap::new();
+        for (name, data) in &networks {
+            let rx_now = data.total_received();
+            let tx_now = data.total_transmitted();
+
+            if let (Some(elapsed), Some(&(rx_prev, tx_prev))) =
+                (elapsed_secs.filter(|&e| e > 0.0), prev_network.get(name))
+            {
+                let rx_rate = rx_now.saturating_sub(rx_prev) as f64 / elapsed;
+                let tx_rate = tx_now.saturating_sub(tx_prev) as f64 / elapsed;
+                network_rates.insert(name.clone(), (rx_rate, tx_rate));
+            }
+
+            prev_network.insert(name.clone(), (rx_now, tx_now));
+        }
+
+        prev_instant = Some(now);
+
+        let snapshot = build_snapshot(&sys, &disks, &network_rates);
+
+        if stream {
+            write_snapshot_line(&snapshot, out);
+        }
+
+        if history.len() >= config.history.history_length {
+            history.pop_front();
+        }
+        history.push_back(snapshot);
+        collected += 1;
+
+        if once || cycles.is_some_and(|target| collected >= target) {
+            break;
+        }
+
         thread::sleep(Duration::from_secs(config.general.refresh_interval));
     }
+
+    if !stream {
+        if let Err(e) = write_history(&history, format, out) {
+            eprintln!("Failed to export history: {}", e);
+            std::process::exit(1);
+        }
+    }
 }
 
 fn main() {
@@ -439,6 +1321,13 @@ fn main() {
     let cli = Cli::parse();
     let config = load_config(&cli.config);
 
+    let filter = cli.filter.as_deref().map(|expr| {
+        query::parse(expr).unwrap_or_else(|e| {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        })
+    });
+
     match cli.command {
         Some(Commands::GenerateConfig) => {
             generate_config(&cli.config);
@@ -446,16 +1335,37 @@ fn main() {
         Some(Commands::Summary) => {
             let mut sys = System::new_all();
             let mut disks = Disks::new_with_refreshed_list();
+            let networks = Networks::new_with_refreshed_list();
+            let components = Components::new_with_refreshed_list();
             sys.refresh_all();
             disks.refresh();
-            display_system_info(&sys, &disks, &config);
+            let snapshot = SystemSnapshot {
+                sys: &sys,
+                disks: &disks,
+                networks: &networks,
+                network_rates: &HashMap::new(),
+                components: &components,
+            };
+            display_system_info(
+                &snapshot,
+                &config,
+                &mut AlertState::new(),
+                filter.as_ref(),
+                false,
+            );
+        }
+        Some(Commands::Kill { target, signal }) => {
+            kill_processes(&config, &target, signal.as_deref(), cli.quiet);
+        }
+        Some(Commands::Export { format, out, stream, cycles }) => {
+            export_history(&config, &format, &out, stream, cli.once, cycles);
         }
         Some(Commands::Monitor) => {
-            run_monitor(&config, cli.once);
+            run_monitor(&config, cli.once, filter.as_ref());
         }
         None => {
             // Default behavior - run monitor
-            run_monitor(&config, cli.once);
+            run_monitor(&config, cli.once, filter.as_ref());
         }
     }
 }
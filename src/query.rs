@@ -0,0 +1,215 @@
+//! Small query language for filtering the top-processes list, e.g.
+//! `name:firefox and cpu>50` or `(mem>200 or pid:1234) and name:chrome`.
+
+use std::fmt;
+
+use sysinfo::Process;
+
+#[derive(Debug, Clone)]
+pub enum Query {
+    And(Box<Query>, Box<Query>),
+    Or(Box<Query>, Box<Query>),
+    Predicate { field: Field, op: Op, value: Value },
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Field {
+    Name,
+    Cpu,
+    Mem,
+    Pid,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Op {
+    Eq,
+    Gt,
+    Lt,
+}
+
+#[derive(Debug, Clone)]
+pub enum Value {
+    Text(String),
+    Number(f64),
+}
+
+#[derive(Debug)]
+pub struct QueryParseError(String);
+
+impl fmt::Display for QueryParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid filter query: {}", self.0)
+    }
+}
+
+impl std::error::Error for QueryParseError {}
+
+/// Parses a filter expression like `cpu>50 and name:firefox` into a `Query`.
+pub fn parse(input: &str) -> Result<Query, QueryParseError> {
+    let tokens = tokenize(input);
+    let mut pos = 0;
+    let query = parse_or(&tokens, &mut pos)?;
+
+    if pos != tokens.len() {
+        return Err(QueryParseError(format!(
+            "unexpected trailing token '{}'",
+            tokens[pos]
+        )));
+    }
+
+    Ok(query)
+}
+
+/// Evaluates a parsed `Query` against a single process.
+pub fn matches(query: &Query, process: &Process) -> bool {
+    match query {
+        Query::And(left, right) => matches(left, process) && matches(right, process),
+        Query::Or(left, right) => matches(left, process) || matches(right, process),
+        Query::Predicate { field, op, value } => matches_predicate(*field, *op, value, process),
+    }
+}
+
+fn tokenize(input: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+
+    for c in input.chars() {
+        match c {
+            '(' | ')' => {
+                if !current.is_empty() {
+                    tokens.push(std::mem::take(&mut current));
+                }
+                tokens.push(c.to_string());
+            }
+            c if c.is_whitespace() => {
+                if !current.is_empty() {
+                    tokens.push(std::mem::take(&mut current));
+                }
+            }
+            c => current.push(c),
+        }
+    }
+
+    if !current.is_empty() {
+        tokens.push(current);
+    }
+
+    tokens
+}
+
+fn parse_or(tokens: &[String], pos: &mut usize) -> Result<Query, QueryParseError> {
+    let mut left = parse_and(tokens, pos)?;
+
+    while is_keyword(tokens.get(*pos), "or") {
+        *pos += 1;
+        let right = parse_and(tokens, pos)?;
+        left = Query::Or(Box::new(left), Box::new(right));
+    }
+
+    Ok(left)
+}
+
+fn parse_and(tokens: &[String], pos: &mut usize) -> Result<Query, QueryParseError> {
+    let mut left = parse_atom(tokens, pos)?;
+
+    while is_keyword(tokens.get(*pos), "and") {
+        *pos += 1;
+        let right = parse_atom(tokens, pos)?;
+        left = Query::And(Box::new(left), Box::new(right));
+    }
+
+    Ok(left)
+}
+
+fn parse_atom(tokens: &[String], pos: &mut usize) -> Result<Query, QueryParseError> {
+    match tokens.get(*pos) {
+        Some(t) if t == "(" => {
+            *pos += 1;
+            let inner = parse_or(tokens, pos)?;
+            match tokens.get(*pos) {
+                Some(t) if t == ")" => {
+                    *pos += 1;
+                    Ok(inner)
+                }
+                _ => Err(QueryParseError("expected closing ')'".to_string())),
+            }
+        }
+        Some(token) => {
+            *pos += 1;
+            parse_predicate(token)
+        }
+        None => Err(QueryParseError("unexpected end of query".to_string())),
+    }
+}
+
+fn parse_predicate(token: &str) -> Result<Query, QueryParseError> {
+    let (field_str, op, value_str) = split_predicate(token)?;
+
+    let field = match field_str.to_ascii_lowercase().as_str() {
+        "name" => Field::Name,
+        "cpu" => Field::Cpu,
+        "mem" | "memory" => Field::Mem,
+        "pid" => Field::Pid,
+        other => return Err(QueryParseError(format!("unknown field '{}'", other))),
+    };
+
+    let value = match field {
+        Field::Name => Value::Text(value_str.to_string()),
+        Field::Cpu | Field::Mem | Field::Pid => {
+            let number = value_str
+                .parse::<f64>()
+                .map_err(|_| QueryParseError(format!("invalid numeric value '{}'", value_str)))?;
+            Value::Number(number)
+        }
+    };
+
+    Ok(Query::Predicate { field, op, value })
+}
+
+fn split_predicate(token: &str) -> Result<(&str, Op, &str), QueryParseError> {
+    for (idx, ch) in token.char_indices() {
+        match ch {
+            ':' => return Ok((&token[..idx], Op::Eq, &token[idx + 1..])),
+            '>' => return Ok((&token[..idx], Op::Gt, &token[idx + 1..])),
+            '<' => return Ok((&token[..idx], Op::Lt, &token[idx + 1..])),
+            _ => {}
+        }
+    }
+
+    Err(QueryParseError(format!(
+        "could not parse predicate '{}' (expected field:value, field>value, or field<value)",
+        token
+    )))
+}
+
+fn is_keyword(token: Option<&String>, keyword: &str) -> bool {
+    token.is_some_and(|t| t.eq_ignore_ascii_case(keyword))
+}
+
+fn matches_predicate(field: Field, op: Op, value: &Value, process: &Process) -> bool {
+    match field {
+        Field::Name => match value {
+            Value::Text(pattern) => match regex::Regex::new(pattern) {
+                Ok(re) => re.is_match(process.name()),
+                Err(_) => process.name().contains(pattern.as_str()),
+            },
+            Value::Number(_) => false,
+        },
+        Field::Cpu => compare_numeric(process.cpu_usage() as f64, op, value),
+        Field::Mem => compare_numeric(process.memory() as f64 / 1024.0 / 1024.0, op, value),
+        Field::Pid => compare_numeric(process.pid().as_u32() as f64, op, value),
+    }
+}
+
+fn compare_numeric(actual: f64, op: Op, value: &Value) -> bool {
+    let target = match value {
+        Value::Number(n) => *n,
+        Value::Text(_) => return false,
+    };
+
+    match op {
+        Op::Eq => (actual - target).abs() < f64::EPSILON,
+        Op::Gt => actual > target,
+        Op::Lt => actual < target,
+    }
+}